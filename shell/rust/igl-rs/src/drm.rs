@@ -0,0 +1,70 @@
+//! Direct DRM/KMS + GBM display backend
+//!
+//! Lets a `Platform` render straight to a KMS CRTC via a GBM-allocated
+//! buffer chain, with no X11/Wayland compositor or window manager present
+//! (embedded/kiosk Linux, or a bare TTY).
+
+use std::ffi::CString;
+use std::path::Path;
+
+use igl_sys::drm::{igl_platform_create_drm, igl_platform_list_drm_connectors, IGLDrmError};
+
+use crate::{Error, Platform, Result};
+
+/// Identifies a DRM connector on a card (as returned by `list_connectors`)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ConnectorId(pub u32);
+
+impl Platform {
+    /// Open the DRM device at `card_path` and render directly to the KMS
+    /// CRTC behind `connector` (or the first connected connector if
+    /// `None`), going through a GBM-allocated buffer chain bound as the
+    /// IGL swapchain.
+    pub fn create_drm(card_path: &Path, connector: Option<ConnectorId>) -> Result<Self> {
+        let path_cstr =
+            CString::new(card_path.to_string_lossy().as_bytes()).map_err(|_| Error::DrmOpenFailed)?;
+        let connector_id = connector.map(|c| c.0 as i32).unwrap_or(-1);
+
+        let mut error = IGLDrmError::None;
+        let handle =
+            unsafe { igl_platform_create_drm(path_cstr.as_ptr(), connector_id, &mut error) };
+        if handle.is_null() {
+            return Err(match error {
+                IGLDrmError::OpenFailed | IGLDrmError::None => Error::DrmOpenFailed,
+                IGLDrmError::NoConnector => Error::NoConnector,
+                IGLDrmError::ModesetFailed => Error::ModesetFailed,
+            });
+        }
+
+        // DRM/KMS output has no single native backend the way
+        // Metal/Vulkan/desktop OpenGL do; IGL's DRM backend renders
+        // through OpenGL ES on embedded Linux.
+        Ok(Platform::from_raw_handle(handle, crate::Backend::OpenGlEs))
+    }
+
+    /// List the connector IDs exposed by the DRM device at `card_path`,
+    /// without opening a platform for any of them.
+    pub fn list_connectors(card_path: &Path) -> Result<Vec<ConnectorId>> {
+        let path_cstr =
+            CString::new(card_path.to_string_lossy().as_bytes()).map_err(|_| Error::DrmOpenFailed)?;
+
+        let mut count: u32 = 0;
+        let sized = unsafe {
+            igl_platform_list_drm_connectors(path_cstr.as_ptr(), std::ptr::null_mut(), &mut count)
+        };
+        if !sized {
+            return Err(Error::DrmOpenFailed);
+        }
+
+        let mut connectors = vec![0u32; count as usize];
+        let success = unsafe {
+            igl_platform_list_drm_connectors(path_cstr.as_ptr(), connectors.as_mut_ptr(), &mut count)
+        };
+        if !success {
+            return Err(Error::DrmOpenFailed);
+        }
+
+        connectors.truncate(count as usize);
+        Ok(connectors.into_iter().map(ConnectorId).collect())
+    }
+}
@@ -7,10 +7,24 @@ use std::ptr;
 
 use crate::{Error, Result};
 
+/// A DRM/V4L2 four-character-code pixel format, as used by dma-buf import
+/// (e.g. `FourCC::new(b'N', b'V', b'1', b'2')` for semi-planar YUV 4:2:0).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FourCC(pub u32);
+
+impl FourCC {
+    pub const fn new(a: u8, b: u8, c: u8, d: u8) -> Self {
+        FourCC(a as u32 | (b as u32) << 8 | (c as u32) << 16 | (d as u32) << 24)
+    }
+}
+
 /// Device represents the graphics device
 pub struct Device {
     pub(crate) handle: *mut IGLDevice,
-    // Not owned, just a reference
+    // Devices obtained from a Platform are borrowed from it and outlive
+    // this struct's Drop; headless devices are owned by this struct and
+    // destroyed in its Drop.
+    owned: bool,
 }
 
 impl Device {
@@ -19,13 +33,42 @@ impl Device {
         if handle.is_null() {
             return Err(Error::NullPointer);
         }
-        Ok(Device { handle })
+        Ok(Device {
+            handle,
+            owned: false,
+        })
+    }
+
+    /// Create a device with an offscreen swapchain (or no swapchain at
+    /// all) and no platform/window handle. Useful for golden-image
+    /// rendering tests and server-side rendering with no window system
+    /// attached.
+    pub fn create_headless(backend: BackendType) -> Result<Self> {
+        let handle = unsafe { igl_device_create_headless(backend.into()) };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(Device {
+            handle,
+            owned: true,
+        })
     }
 
     pub fn backend_type(&self) -> BackendType {
         unsafe { igl_device_get_backend_type(self.handle).into() }
     }
 
+    /// Create an offscreen color target usable as a framebuffer
+    /// attachment without a platform-provided drawable.
+    pub fn create_offscreen_texture(
+        &self,
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+    ) -> Result<Texture> {
+        Texture::new_offscreen(self, format, width, height)
+    }
+
     pub fn create_command_queue(&self) -> Result<CommandQueue> {
         CommandQueue::new(self)
     }
@@ -43,6 +86,41 @@ impl Device {
         ShaderStages::new_metal(self, source, vertex_entry, fragment_entry)
     }
 
+    /// Create compute-only shader stages holding a single kernel function,
+    /// for use with `create_compute_pipeline`.
+    pub fn create_shader_stages_compute_metal(
+        &self,
+        source: &str,
+        kernel_entry: &str,
+    ) -> Result<ShaderStages> {
+        ShaderStages::new_compute_metal(self, source, kernel_entry)
+    }
+
+    /// Compile GLSL vertex/fragment sources and cross-compile them to the
+    /// active backend's shading language (MSL on Metal, GLSL/ESSL on
+    /// OpenGL) via glslang + spirv-cross.
+    pub fn create_shader_stages_glsl(
+        &self,
+        vertex_source: &str,
+        fragment_source: &str,
+        vertex_entry: &str,
+        fragment_entry: &str,
+    ) -> Result<ShaderStages> {
+        ShaderStages::new_glsl(self, vertex_source, fragment_source, vertex_entry, fragment_entry)
+    }
+
+    /// Cross-compile pre-built SPIR-V binaries to the active backend's
+    /// shading language.
+    pub fn create_shader_stages_spirv(
+        &self,
+        vertex_spirv: &[u32],
+        fragment_spirv: &[u32],
+        vertex_entry: &str,
+        fragment_entry: &str,
+    ) -> Result<ShaderStages> {
+        ShaderStages::new_spirv(self, vertex_spirv, fragment_spirv, vertex_entry, fragment_entry)
+    }
+
     pub fn create_vertex_input_state(
         &self,
         attributes: &[VertexAttribute],
@@ -59,6 +137,7 @@ impl Device {
         Framebuffer::new(self, color_texture, depth_texture)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_render_pipeline(
         &self,
         vertex_input: &VertexInputState,
@@ -67,6 +146,7 @@ impl Device {
         depth_format: TextureFormat,
         cull_mode: CullMode,
         winding_mode: WindingMode,
+        blend: BlendState,
     ) -> Result<RenderPipelineState> {
         RenderPipelineState::new(
             self,
@@ -76,8 +156,85 @@ impl Device {
             depth_format,
             cull_mode,
             winding_mode,
+            blend,
         )
     }
+
+    pub fn create_compute_pipeline(&self, shaders: &ShaderStages) -> Result<ComputePipelineState> {
+        ComputePipelineState::new(self, shaders)
+    }
+
+    /// `stencil` is `Some((front, back))` to enable the stencil test with
+    /// per-face operations, or `None` to leave it disabled.
+    pub fn create_depth_stencil_state(
+        &self,
+        compare_function: CompareFunction,
+        depth_write_enabled: bool,
+        stencil: Option<(StencilFaceState, StencilFaceState)>,
+    ) -> Result<DepthStencilState> {
+        DepthStencilState::new(self, compare_function, depth_write_enabled, stencil)
+    }
+
+    /// Open (creating if needed) an on-disk cache for this device's
+    /// compiled pipeline/shader artifacts at `path`. Once open, every
+    /// subsequent `create_render_pipeline`/`create_shader_stages_*` call
+    /// hashes its shader source (or SPIR-V bytes), vertex layout, and
+    /// attachment formats, and consults the cache before compiling,
+    /// eliminating shader/pipeline recompilation on repeat launches.
+    pub fn create_pipeline_cache(&self, path: &std::path::Path) -> Result<PipelineCache> {
+        PipelineCache::new(self, path)
+    }
+
+    /// Create a sampled/attachment/storage texture with `mip_count` mip
+    /// levels. Upload pixel data afterwards with `Texture::upload`.
+    pub fn create_texture(
+        &self,
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+        usage: TextureUsage,
+        mip_count: u32,
+    ) -> Result<Texture> {
+        Texture::new(self, format, width, height, usage, mip_count)
+    }
+
+    pub fn create_sampler(&self, descriptor: SamplerDescriptor) -> Result<SamplerState> {
+        SamplerState::new(self, descriptor)
+    }
+
+    /// List the DRM fourcc formats this device can zero-copy import via
+    /// `Texture::import_dmabuf`.
+    pub fn supported_dmabuf_formats(&self) -> Result<Vec<FourCC>> {
+        let mut count: u32 = 0;
+        let sized = unsafe {
+            igl_device_supported_dmabuf_formats(self.handle, ptr::null_mut(), &mut count)
+        };
+        if !sized {
+            return Err(Error::BufferImportFailed);
+        }
+
+        let mut formats = vec![0u32; count as usize];
+        let success = unsafe {
+            igl_device_supported_dmabuf_formats(self.handle, formats.as_mut_ptr(), &mut count)
+        };
+        if !success {
+            return Err(Error::BufferImportFailed);
+        }
+
+        formats.truncate(count as usize);
+        Ok(formats.into_iter().map(FourCC).collect())
+    }
+}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        // Devices borrowed from a Platform are not ours to destroy.
+        if self.owned && !self.handle.is_null() {
+            unsafe {
+                igl_device_destroy(self.handle);
+            }
+        }
+    }
 }
 
 // Not Send/Sync - tied to graphics context
@@ -102,6 +259,17 @@ impl From<IGLBackendType> for BackendType {
     }
 }
 
+impl From<BackendType> for IGLBackendType {
+    fn from(t: BackendType) -> Self {
+        match t {
+            BackendType::Invalid => IGLBackendType::Invalid,
+            BackendType::OpenGL => IGLBackendType::OpenGL,
+            BackendType::Metal => IGLBackendType::Metal,
+            BackendType::Vulkan => IGLBackendType::Vulkan,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum BufferType {
     Vertex,
@@ -185,6 +353,21 @@ impl From<WindingMode> for IGLWindingMode {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InputRate {
+    Vertex,
+    Instance,
+}
+
+impl From<InputRate> for IGLVertexInputRate {
+    fn from(r: InputRate) -> Self {
+        match r {
+            InputRate::Vertex => IGLVertexInputRate::Vertex,
+            InputRate::Instance => IGLVertexInputRate::Instance,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum LoadAction {
     DontCare,
@@ -255,21 +438,315 @@ pub struct VertexAttribute {
     pub offset: u32,
     pub name: String,
     pub location: i32,
+    // Normally matches the `VertexBinding` it's sourced from; set
+    // differently only for backends/layouts that key step rate per
+    // attribute rather than per buffer binding.
+    pub input_rate: InputRate,
 }
 
 #[derive(Debug, Copy, Clone)]
 pub struct VertexBinding {
     pub stride: u32,
+    pub input_rate: InputRate,
 }
 
-// Texture format - simplified for now
+#[allow(non_camel_case_types)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u32)]
 pub enum TextureFormat {
+    R_UNorm8 = 1,
+    RG_UNorm8 = 2,
+    RGBA_UNorm8 = 4,
+    RGBA_SRGB = 5,
+    RGBA_F16 = 20,
     BGRA_UNorm8 = 10,
+    BGRA_SRGB = 11,
     Depth32Float = 41,
 }
 
+/// Bitmask of how a texture will be used. Bits combine with `|`, e.g.
+/// `TextureUsage::SAMPLED | TextureUsage::ATTACHMENT` for an offscreen
+/// render target that's later bound as a shader input.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextureUsage(u32);
+
+impl TextureUsage {
+    pub const SAMPLED: TextureUsage = TextureUsage(IGL_TEXTURE_USAGE_SAMPLED);
+    pub const ATTACHMENT: TextureUsage = TextureUsage(IGL_TEXTURE_USAGE_ATTACHMENT);
+    pub const STORAGE: TextureUsage = TextureUsage(IGL_TEXTURE_USAGE_STORAGE);
+}
+
+impl std::ops::BitOr for TextureUsage {
+    type Output = TextureUsage;
+
+    fn bitor(self, rhs: Self) -> Self {
+        TextureUsage(self.0 | rhs.0)
+    }
+}
+
+impl From<TextureUsage> for IGLTextureUsage {
+    fn from(u: TextureUsage) -> Self {
+        u.0
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SamplerFilter {
+    Nearest,
+    Linear,
+}
+
+impl From<SamplerFilter> for IGLSamplerFilter {
+    fn from(f: SamplerFilter) -> Self {
+        match f {
+            SamplerFilter::Nearest => IGLSamplerFilter::Nearest,
+            SamplerFilter::Linear => IGLSamplerFilter::Linear,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SamplerWrapMode {
+    Repeat,
+    Clamp,
+    MirrorRepeat,
+}
+
+impl From<SamplerWrapMode> for IGLSamplerWrapMode {
+    fn from(m: SamplerWrapMode) -> Self {
+        match m {
+            SamplerWrapMode::Repeat => IGLSamplerWrapMode::Repeat,
+            SamplerWrapMode::Clamp => IGLSamplerWrapMode::Clamp,
+            SamplerWrapMode::MirrorRepeat => IGLSamplerWrapMode::MirrorRepeat,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CompareFunction {
+    Never,
+    Less,
+    LessEqual,
+    Greater,
+    Equal,
+    Always,
+}
+
+impl From<CompareFunction> for IGLCompareFunction {
+    fn from(f: CompareFunction) -> Self {
+        match f {
+            CompareFunction::Never => IGLCompareFunction::Never,
+            CompareFunction::Less => IGLCompareFunction::Less,
+            CompareFunction::LessEqual => IGLCompareFunction::LessEqual,
+            CompareFunction::Greater => IGLCompareFunction::Greater,
+            CompareFunction::Equal => IGLCompareFunction::Equal,
+            CompareFunction::Always => IGLCompareFunction::Always,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlendFactor {
+    Zero,
+    One,
+    SrcColor,
+    OneMinusSrcColor,
+    SrcAlpha,
+    OneMinusSrcAlpha,
+    DstColor,
+    OneMinusDstColor,
+    DstAlpha,
+    OneMinusDstAlpha,
+}
+
+impl From<BlendFactor> for IGLBlendFactor {
+    fn from(f: BlendFactor) -> Self {
+        match f {
+            BlendFactor::Zero => IGLBlendFactor::Zero,
+            BlendFactor::One => IGLBlendFactor::One,
+            BlendFactor::SrcColor => IGLBlendFactor::SrcColor,
+            BlendFactor::OneMinusSrcColor => IGLBlendFactor::OneMinusSrcColor,
+            BlendFactor::SrcAlpha => IGLBlendFactor::SrcAlpha,
+            BlendFactor::OneMinusSrcAlpha => IGLBlendFactor::OneMinusSrcAlpha,
+            BlendFactor::DstColor => IGLBlendFactor::DstColor,
+            BlendFactor::OneMinusDstColor => IGLBlendFactor::OneMinusDstColor,
+            BlendFactor::DstAlpha => IGLBlendFactor::DstAlpha,
+            BlendFactor::OneMinusDstAlpha => IGLBlendFactor::OneMinusDstAlpha,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlendOp {
+    Add,
+    Subtract,
+    ReverseSubtract,
+}
+
+impl From<BlendOp> for IGLBlendOp {
+    fn from(op: BlendOp) -> Self {
+        match op {
+            BlendOp::Add => IGLBlendOp::Add,
+            BlendOp::Subtract => IGLBlendOp::Subtract,
+            BlendOp::ReverseSubtract => IGLBlendOp::ReverseSubtract,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StencilOperation {
+    Keep,
+    Zero,
+    Replace,
+    IncrementClamp,
+    DecrementClamp,
+    Invert,
+    IncrementWrap,
+    DecrementWrap,
+}
+
+impl From<StencilOperation> for IGLStencilOperation {
+    fn from(op: StencilOperation) -> Self {
+        match op {
+            StencilOperation::Keep => IGLStencilOperation::Keep,
+            StencilOperation::Zero => IGLStencilOperation::Zero,
+            StencilOperation::Replace => IGLStencilOperation::Replace,
+            StencilOperation::IncrementClamp => IGLStencilOperation::IncrementClamp,
+            StencilOperation::DecrementClamp => IGLStencilOperation::DecrementClamp,
+            StencilOperation::Invert => IGLStencilOperation::Invert,
+            StencilOperation::IncrementWrap => IGLStencilOperation::IncrementWrap,
+            StencilOperation::DecrementWrap => IGLStencilOperation::DecrementWrap,
+        }
+    }
+}
+
+/// Per-face stencil test configuration
+#[derive(Debug, Copy, Clone)]
+pub struct StencilFaceState {
+    pub stencil_fail_op: StencilOperation,
+    pub depth_fail_op: StencilOperation,
+    pub pass_op: StencilOperation,
+    pub compare_function: CompareFunction,
+    pub read_mask: u32,
+    pub write_mask: u32,
+}
+
+impl Default for StencilFaceState {
+    fn default() -> Self {
+        Self {
+            stencil_fail_op: StencilOperation::Keep,
+            depth_fail_op: StencilOperation::Keep,
+            pass_op: StencilOperation::Keep,
+            compare_function: CompareFunction::Always,
+            read_mask: 0xff,
+            write_mask: 0xff,
+        }
+    }
+}
+
+impl From<StencilFaceState> for IGLStencilFaceDescriptor {
+    fn from(s: StencilFaceState) -> Self {
+        IGLStencilFaceDescriptor {
+            stencil_fail_op: s.stencil_fail_op.into(),
+            depth_fail_op: s.depth_fail_op.into(),
+            pass_op: s.pass_op.into(),
+            compare_function: s.compare_function.into(),
+            read_mask: s.read_mask,
+            write_mask: s.write_mask,
+        }
+    }
+}
+
+/// Per-color-attachment blend configuration for a render pipeline
+#[derive(Debug, Copy, Clone)]
+pub struct BlendState {
+    pub enabled: bool,
+    pub src_color_factor: BlendFactor,
+    pub dst_color_factor: BlendFactor,
+    pub color_op: BlendOp,
+    pub src_alpha_factor: BlendFactor,
+    pub dst_alpha_factor: BlendFactor,
+    pub alpha_op: BlendOp,
+}
+
+impl Default for BlendState {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            src_color_factor: BlendFactor::One,
+            dst_color_factor: BlendFactor::Zero,
+            color_op: BlendOp::Add,
+            src_alpha_factor: BlendFactor::One,
+            dst_alpha_factor: BlendFactor::Zero,
+            alpha_op: BlendOp::Add,
+        }
+    }
+}
+
+impl BlendState {
+    /// Standard `src * srcAlpha + dst * (1 - srcAlpha)` alpha blending,
+    /// for translucent geometry and composited UI.
+    pub fn alpha_blend() -> Self {
+        Self {
+            enabled: true,
+            src_color_factor: BlendFactor::SrcAlpha,
+            dst_color_factor: BlendFactor::OneMinusSrcAlpha,
+            color_op: BlendOp::Add,
+            src_alpha_factor: BlendFactor::One,
+            dst_alpha_factor: BlendFactor::OneMinusSrcAlpha,
+            alpha_op: BlendOp::Add,
+        }
+    }
+
+    /// `src * srcAlpha + dst`, for additive particle and glow effects.
+    pub fn additive() -> Self {
+        Self {
+            enabled: true,
+            src_color_factor: BlendFactor::SrcAlpha,
+            dst_color_factor: BlendFactor::One,
+            color_op: BlendOp::Add,
+            src_alpha_factor: BlendFactor::One,
+            dst_alpha_factor: BlendFactor::One,
+            alpha_op: BlendOp::Add,
+        }
+    }
+}
+
+impl From<BlendState> for IGLBlendDescriptor {
+    fn from(b: BlendState) -> Self {
+        IGLBlendDescriptor {
+            enabled: b.enabled,
+            src_color_factor: b.src_color_factor.into(),
+            dst_color_factor: b.dst_color_factor.into(),
+            color_op: b.color_op.into(),
+            src_alpha_factor: b.src_alpha_factor.into(),
+            dst_alpha_factor: b.dst_alpha_factor.into(),
+            alpha_op: b.alpha_op.into(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SamplerDescriptor {
+    pub min_filter: SamplerFilter,
+    pub mag_filter: SamplerFilter,
+    pub mip_filter: SamplerFilter,
+    pub wrap_u: SamplerWrapMode,
+    pub wrap_v: SamplerWrapMode,
+}
+
+impl Default for SamplerDescriptor {
+    fn default() -> Self {
+        Self {
+            min_filter: SamplerFilter::Linear,
+            mag_filter: SamplerFilter::Linear,
+            mip_filter: SamplerFilter::Linear,
+            wrap_u: SamplerWrapMode::Repeat,
+            wrap_v: SamplerWrapMode::Repeat,
+        }
+    }
+}
+
 /// Command Queue manages command submission
 pub struct CommandQueue {
     handle: *mut IGLCommandQueue,
@@ -330,6 +807,14 @@ impl CommandBuffer {
         RenderCommandEncoder::new(self, framebuffer, color_attachment, depth_attachment)
     }
 
+    pub fn create_compute_encoder(&self) -> Result<ComputeCommandEncoder> {
+        ComputeCommandEncoder::new(self)
+    }
+
+    pub fn create_blit_encoder(&self) -> Result<BlitCommandEncoder> {
+        BlitCommandEncoder::new(self)
+    }
+
     pub fn present(&self, texture: &Texture) {
         unsafe {
             igl_command_buffer_present(self.handle, texture.handle);
@@ -417,9 +902,23 @@ impl Drop for Buffer {
 
 unsafe impl Send for Buffer {}
 
-/// Shader stages contain vertex and fragment shaders
+/// Which source representation a `ShaderStages` was built from. Kept
+/// alongside the handle so a single pipeline-building path can be shared
+/// across backends without the caller re-deriving it from how the shader
+/// was loaded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ShaderSourceKind {
+    Metal,
+    ComputeMetal,
+    Glsl,
+    Spirv,
+}
+
+/// Shader stages contain vertex and fragment shaders (or a single compute
+/// kernel), sourced from Metal, GLSL, or SPIR-V.
 pub struct ShaderStages {
     handle: *mut IGLShaderStages,
+    source_kind: ShaderSourceKind,
 }
 
 impl ShaderStages {
@@ -444,7 +943,101 @@ impl ShaderStages {
         if handle.is_null() {
             return Err(Error::NullPointer);
         }
-        Ok(ShaderStages { handle })
+        Ok(ShaderStages {
+            handle,
+            source_kind: ShaderSourceKind::Metal,
+        })
+    }
+
+    fn new_compute_metal(device: &Device, source: &str, kernel_entry: &str) -> Result<Self> {
+        let source_cstr = CString::new(source).map_err(|_| Error::NullPointer)?;
+        let kernel_cstr = CString::new(kernel_entry).map_err(|_| Error::NullPointer)?;
+
+        let handle = unsafe {
+            igl_device_create_shader_stages_compute_metal(
+                device.handle,
+                source_cstr.as_ptr(),
+                kernel_cstr.as_ptr(),
+            )
+        };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(ShaderStages {
+            handle,
+            source_kind: ShaderSourceKind::ComputeMetal,
+        })
+    }
+
+    fn new_glsl(
+        device: &Device,
+        vertex_source: &str,
+        fragment_source: &str,
+        vertex_entry: &str,
+        fragment_entry: &str,
+    ) -> Result<Self> {
+        let vertex_cstr = CString::new(vertex_source).map_err(|_| Error::NullPointer)?;
+        let fragment_cstr = CString::new(fragment_source).map_err(|_| Error::NullPointer)?;
+        let vertex_entry_cstr = CString::new(vertex_entry).map_err(|_| Error::NullPointer)?;
+        let fragment_entry_cstr = CString::new(fragment_entry).map_err(|_| Error::NullPointer)?;
+
+        let mut error_log: *mut std::os::raw::c_char = ptr::null_mut();
+        let handle = unsafe {
+            igl_device_create_shader_stages_from_glsl(
+                device.handle,
+                vertex_cstr.as_ptr(),
+                fragment_cstr.as_ptr(),
+                vertex_entry_cstr.as_ptr(),
+                fragment_entry_cstr.as_ptr(),
+                &mut error_log,
+            )
+        };
+
+        if handle.is_null() {
+            return Err(Error::ShaderCompilationFailed(take_error_log(error_log)));
+        }
+        Ok(ShaderStages {
+            handle,
+            source_kind: ShaderSourceKind::Glsl,
+        })
+    }
+
+    fn new_spirv(
+        device: &Device,
+        vertex_spirv: &[u32],
+        fragment_spirv: &[u32],
+        vertex_entry: &str,
+        fragment_entry: &str,
+    ) -> Result<Self> {
+        let vertex_entry_cstr = CString::new(vertex_entry).map_err(|_| Error::NullPointer)?;
+        let fragment_entry_cstr = CString::new(fragment_entry).map_err(|_| Error::NullPointer)?;
+
+        let mut error_log: *mut std::os::raw::c_char = ptr::null_mut();
+        let handle = unsafe {
+            igl_device_create_shader_stages_from_spirv(
+                device.handle,
+                vertex_spirv.as_ptr(),
+                vertex_spirv.len() as u32,
+                fragment_spirv.as_ptr(),
+                fragment_spirv.len() as u32,
+                vertex_entry_cstr.as_ptr(),
+                fragment_entry_cstr.as_ptr(),
+                &mut error_log,
+            )
+        };
+
+        if handle.is_null() {
+            return Err(Error::ShaderCompilationFailed(take_error_log(error_log)));
+        }
+        Ok(ShaderStages {
+            handle,
+            source_kind: ShaderSourceKind::Spirv,
+        })
+    }
+
+    /// Which source representation this shader was built from.
+    pub fn source_kind(&self) -> ShaderSourceKind {
+        self.source_kind
     }
 
     pub(crate) fn as_ptr(&self) -> *mut IGLShaderStages {
@@ -452,6 +1045,21 @@ impl ShaderStages {
     }
 }
 
+/// Converts an optional error log returned via an `out_error_log` pointer
+/// into an owned `String`, freeing the C-allocated buffer.
+fn take_error_log(log: *mut std::os::raw::c_char) -> String {
+    if log.is_null() {
+        return String::from("unknown shader compilation error");
+    }
+    let message = unsafe { std::ffi::CStr::from_ptr(log) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe {
+        igl_free_error_log(log);
+    }
+    message
+}
+
 impl Drop for ShaderStages {
     fn drop(&mut self) {
         if !self.handle.is_null() {
@@ -488,13 +1096,17 @@ impl VertexInputState {
                 offset: attr.offset,
                 name: name.as_ptr(),
                 location: attr.location,
+                input_rate: attr.input_rate.into(),
             });
             attribute_names.push(name);
         }
 
         let c_bindings: Vec<IGLVertexBinding> = bindings
             .iter()
-            .map(|b| IGLVertexBinding { stride: b.stride })
+            .map(|b| IGLVertexBinding {
+                stride: b.stride,
+                input_rate: b.input_rate.into(),
+            })
             .collect();
 
         let handle = unsafe {
@@ -548,6 +1160,151 @@ impl Texture {
         }
     }
 
+    fn new(
+        device: &Device,
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+        usage: TextureUsage,
+        mip_count: u32,
+    ) -> Result<Self> {
+        let handle = unsafe {
+            igl_device_create_texture_2d(
+                device.handle,
+                format as u32,
+                width,
+                height,
+                usage.into(),
+                mip_count,
+            )
+        };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(Texture {
+            handle,
+            owned: true,
+        })
+    }
+
+    fn new_offscreen(device: &Device, format: TextureFormat, width: u32, height: u32) -> Result<Self> {
+        let handle = unsafe {
+            igl_device_create_offscreen_texture(device.handle, format as u32, width, height)
+        };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(Texture {
+            handle,
+            owned: true,
+        })
+    }
+
+    /// Wrap an externally-allocated dma-buf as a texture with no
+    /// host-side copy. `fds`, `strides`, `offsets`, and `modifiers` each
+    /// have one entry per plane (e.g. 2 for semi-planar NV12).
+    ///
+    /// # Safety
+    /// Each fd in `fds` must be a valid, open dma-buf file descriptor
+    /// describing a buffer laid out per `strides`/`offsets`/`modifiers`,
+    /// and it must stay valid (not closed or resized) for as long as the
+    /// returned `Texture` is used.
+    pub unsafe fn import_dmabuf(
+        device: &Device,
+        fds: &[std::os::unix::io::RawFd],
+        strides: &[u32],
+        offsets: &[u32],
+        modifiers: &[u64],
+        format: FourCC,
+        width: u32,
+        height: u32,
+    ) -> Result<Self> {
+        let plane_count = fds.len();
+        if strides.len() != plane_count || offsets.len() != plane_count || modifiers.len() != plane_count {
+            return Err(Error::BufferImportFailed);
+        }
+
+        let handle = unsafe {
+            igl_device_import_dmabuf_texture(
+                device.handle,
+                fds.as_ptr(),
+                strides.as_ptr(),
+                offsets.as_ptr(),
+                modifiers.as_ptr(),
+                plane_count as u32,
+                format.0,
+                width,
+                height,
+            )
+        };
+        if handle.is_null() {
+            return Err(Error::BufferImportFailed);
+        }
+        Ok(Texture {
+            handle,
+            owned: true,
+        })
+    }
+
+    /// Wrap an existing EGLImage (e.g. from another GL/EGL context, or a
+    /// platform media-decode surface) as a texture with no host-side copy.
+    ///
+    /// # Safety
+    /// `egl_image` must be a valid, non-destroyed `EGLImageKHR`.
+    pub unsafe fn import_egl_image(
+        device: &Device,
+        egl_image: *mut c_void,
+        width: u32,
+        height: u32,
+    ) -> Result<Self> {
+        let handle =
+            unsafe { igl_device_import_egl_image_texture(device.handle, egl_image, width, height) };
+        if handle.is_null() {
+            return Err(Error::BufferImportFailed);
+        }
+        Ok(Texture {
+            handle,
+            owned: true,
+        })
+    }
+
+    /// Reads back the contents of the given mip level into a newly
+    /// allocated buffer. `byte_size` must be large enough to hold the
+    /// mip's pixel data (width * height * bytes-per-pixel).
+    pub fn read_pixels(&self, mip_level: u32, byte_size: usize) -> Result<Vec<u8>> {
+        let mut data = vec![0u8; byte_size];
+        let mut size = byte_size as u32;
+        let success = unsafe {
+            igl_texture_read_pixels(
+                self.handle,
+                mip_level,
+                data.as_mut_ptr() as *mut c_void,
+                &mut size,
+            )
+        };
+        if !success {
+            return Err(Error::NullPointer);
+        }
+        data.truncate(size as usize);
+        Ok(data)
+    }
+
+    pub fn upload(&self, data: &[u8], mip_level: u32) -> Result<()> {
+        let success = unsafe {
+            igl_texture_upload(
+                self.handle,
+                mip_level,
+                data.as_ptr() as *const c_void,
+                data.len() as u32,
+            )
+        };
+        if success {
+            Ok(())
+        } else {
+            Err(Error::NullPointer)
+        }
+    }
+
     pub fn format(&self) -> u32 {
         unsafe { igl_texture_get_format(self.handle) }
     }
@@ -555,15 +1312,119 @@ impl Texture {
     pub fn aspect_ratio(&self) -> f32 {
         unsafe { igl_texture_get_aspect_ratio(self.handle) }
     }
+
+    pub fn width(&self) -> u32 {
+        unsafe { igl_texture_get_width(self.handle) }
+    }
+
+    pub fn height(&self) -> u32 {
+        unsafe { igl_texture_get_height(self.handle) }
+    }
 }
 
 impl Drop for Texture {
     fn drop(&mut self) {
-        // Don't destroy textures we don't own (from platform)
-        // The platform manages their lifecycle
+        // Textures obtained from the platform (owned = false) are not ours
+        // to destroy; the platform manages their lifecycle.
+        if self.owned && !self.handle.is_null() {
+            unsafe {
+                igl_texture_destroy(self.handle);
+            }
+        }
     }
 }
 
+unsafe impl Send for Texture {}
+
+/// Sampler State describes how a texture is filtered and addressed
+pub struct SamplerState {
+    handle: *mut IGLSamplerState,
+}
+
+impl SamplerState {
+    fn new(device: &Device, descriptor: SamplerDescriptor) -> Result<Self> {
+        let c_descriptor = IGLSamplerDescriptor {
+            min_filter: descriptor.min_filter.into(),
+            mag_filter: descriptor.mag_filter.into(),
+            mip_filter: descriptor.mip_filter.into(),
+            wrap_u: descriptor.wrap_u.into(),
+            wrap_v: descriptor.wrap_v.into(),
+        };
+        let handle = unsafe { igl_device_create_sampler(device.handle, &c_descriptor) };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(SamplerState { handle })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut IGLSamplerState {
+        self.handle
+    }
+}
+
+impl Drop for SamplerState {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                igl_sampler_state_destroy(self.handle);
+            }
+        }
+    }
+}
+
+/// An on-disk cache for a `Device`'s compiled pipeline/shader artifacts,
+/// opened with `Device::create_pipeline_cache`. Entries are keyed by a
+/// hash of each pipeline's shader source (or SPIR-V bytes), vertex
+/// layout, and attachment formats, so a cache hit skips shader
+/// compilation entirely on the next launch.
+pub struct PipelineCache {
+    handle: *mut IGLPipelineCache,
+}
+
+impl PipelineCache {
+    fn new(device: &Device, path: &std::path::Path) -> Result<Self> {
+        let path_cstr = CString::new(path.to_string_lossy().as_bytes())
+            .map_err(|_| Error::PipelineCacheFailed)?;
+        let handle = unsafe { igl_device_create_pipeline_cache(device.handle, path_cstr.as_ptr()) };
+        if handle.is_null() {
+            return Err(Error::PipelineCacheFailed);
+        }
+        Ok(PipelineCache { handle })
+    }
+
+    /// Write any pipeline/shader artifacts compiled this session back to
+    /// the cache directory.
+    pub fn flush(&self) -> Result<()> {
+        if unsafe { igl_pipeline_cache_flush(self.handle) } {
+            Ok(())
+        } else {
+            Err(Error::PipelineCacheFailed)
+        }
+    }
+
+    /// Load previously cached pipeline/shader artifacts from the cache
+    /// directory ahead of time, instead of lazily on first use.
+    pub fn load(&self) -> Result<()> {
+        if unsafe { igl_pipeline_cache_load(self.handle) } {
+            Ok(())
+        } else {
+            Err(Error::PipelineCacheFailed)
+        }
+    }
+}
+
+impl Drop for PipelineCache {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                igl_pipeline_cache_destroy(self.handle);
+            }
+        }
+    }
+}
+
+unsafe impl Send for SamplerState {}
+
 /// Framebuffer contains render targets
 pub struct Framebuffer {
     handle: *mut IGLFramebuffer,
@@ -623,7 +1484,9 @@ impl RenderPipelineState {
         depth_format: TextureFormat,
         cull_mode: CullMode,
         winding_mode: WindingMode,
+        blend: BlendState,
     ) -> Result<Self> {
+        let c_blend: IGLBlendDescriptor = blend.into();
         let handle = unsafe {
             igl_device_create_render_pipeline(
                 device.handle,
@@ -633,6 +1496,7 @@ impl RenderPipelineState {
                 depth_format as u32,
                 cull_mode.into(),
                 winding_mode.into(),
+                &c_blend,
             )
         };
         if handle.is_null() {
@@ -658,6 +1522,54 @@ impl Drop for RenderPipelineState {
 
 unsafe impl Send for RenderPipelineState {}
 
+/// Depth-Stencil State configures depth testing independently of the
+/// render pipeline
+pub struct DepthStencilState {
+    handle: *mut IGLDepthStencilState,
+}
+
+impl DepthStencilState {
+    fn new(
+        device: &Device,
+        compare_function: CompareFunction,
+        depth_write_enabled: bool,
+        stencil: Option<(StencilFaceState, StencilFaceState)>,
+    ) -> Result<Self> {
+        let (stencil_enabled, front, back) = match stencil {
+            Some((front, back)) => (true, front, back),
+            None => (false, StencilFaceState::default(), StencilFaceState::default()),
+        };
+        let descriptor = IGLDepthStencilDescriptor {
+            compare_function: compare_function.into(),
+            depth_write_enabled,
+            stencil_enabled,
+            front: front.into(),
+            back: back.into(),
+        };
+        let handle = unsafe { igl_device_create_depth_stencil_state(device.handle, &descriptor) };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(DepthStencilState { handle })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut IGLDepthStencilState {
+        self.handle
+    }
+}
+
+impl Drop for DepthStencilState {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                igl_depth_stencil_state_destroy(self.handle);
+            }
+        }
+    }
+}
+
+unsafe impl Send for DepthStencilState {}
+
 /// Render Command Encoder records rendering commands
 pub struct RenderCommandEncoder {
     handle: *mut IGLRenderCommandEncoder,
@@ -721,12 +1633,61 @@ impl RenderCommandEncoder {
         }
     }
 
+    pub fn bind_texture(&self, index: u32, texture: &Texture) {
+        unsafe {
+            igl_render_encoder_bind_texture(self.handle, index, texture.handle);
+        }
+    }
+
+    pub fn bind_sampler(&self, index: u32, sampler: &SamplerState) {
+        unsafe {
+            igl_render_encoder_bind_sampler(self.handle, index, sampler.as_ptr());
+        }
+    }
+
+    pub fn bind_depth_stencil_state(&self, state: &DepthStencilState) {
+        unsafe {
+            igl_render_encoder_bind_depth_stencil_state(self.handle, state.as_ptr());
+        }
+    }
+
     pub fn draw_indexed(&self, index_count: u32) {
         unsafe {
             igl_render_encoder_draw_indexed(self.handle, index_count);
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_indexed_instanced(
+        &self,
+        index_count: u32,
+        instance_count: u32,
+        first_index: u32,
+        base_vertex: i32,
+        base_instance: u32,
+    ) {
+        unsafe {
+            igl_render_encoder_draw_indexed_instanced(
+                self.handle,
+                index_count,
+                instance_count,
+                first_index,
+                base_vertex,
+                base_instance,
+            );
+        }
+    }
+
+    pub fn draw_indexed_indirect(&self, indirect_buffer: &Buffer, indirect_buffer_offset: u32) {
+        unsafe {
+            igl_render_encoder_draw_indexed_indirect(
+                self.handle,
+                indirect_buffer.as_ptr(),
+                indirect_buffer_offset,
+            );
+        }
+    }
+
     pub fn end_encoding(self) {
         unsafe {
             igl_render_encoder_end_encoding(self.handle);
@@ -746,3 +1707,156 @@ impl Drop for RenderCommandEncoder {
         }
     }
 }
+
+/// Compute Pipeline State wraps a compiled compute kernel
+pub struct ComputePipelineState {
+    handle: *mut IGLComputePipelineState,
+}
+
+impl ComputePipelineState {
+    fn new(device: &Device, shaders: &ShaderStages) -> Result<Self> {
+        let handle =
+            unsafe { igl_device_create_compute_pipeline(device.handle, shaders.as_ptr()) };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(ComputePipelineState { handle })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *mut IGLComputePipelineState {
+        self.handle
+    }
+}
+
+impl Drop for ComputePipelineState {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                igl_compute_pipeline_state_destroy(self.handle);
+            }
+        }
+    }
+}
+
+unsafe impl Send for ComputePipelineState {}
+
+/// Compute Command Encoder records compute dispatch commands
+pub struct ComputeCommandEncoder {
+    handle: *mut IGLComputeCommandEncoder,
+}
+
+impl ComputeCommandEncoder {
+    fn new(command_buffer: &CommandBuffer) -> Result<Self> {
+        let handle =
+            unsafe { igl_command_buffer_create_compute_encoder(command_buffer.handle) };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(ComputeCommandEncoder { handle })
+    }
+
+    pub fn bind_pipeline(&self, pipeline: &ComputePipelineState) {
+        unsafe {
+            igl_compute_encoder_bind_pipeline(self.handle, pipeline.as_ptr());
+        }
+    }
+
+    pub fn bind_buffer(&self, index: u32, buffer: &Buffer) {
+        unsafe {
+            igl_compute_encoder_bind_buffer(self.handle, index, buffer.as_ptr());
+        }
+    }
+
+    pub fn bind_texture(&self, index: u32, texture: &Texture) {
+        unsafe {
+            igl_compute_encoder_bind_texture(self.handle, index, texture.handle);
+        }
+    }
+
+    pub fn dispatch(&self, threadgroups: (u32, u32, u32), threads_per_group: (u32, u32, u32)) {
+        unsafe {
+            igl_compute_encoder_dispatch(
+                self.handle,
+                threadgroups.0,
+                threadgroups.1,
+                threadgroups.2,
+                threads_per_group.0,
+                threads_per_group.1,
+                threads_per_group.2,
+            );
+        }
+    }
+
+    pub fn end_encoding(self) {
+        unsafe {
+            igl_compute_encoder_end_encoding(self.handle);
+        }
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for ComputeCommandEncoder {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                igl_compute_encoder_end_encoding(self.handle);
+            }
+        }
+    }
+}
+
+/// Blit Command Encoder records GPU-to-GPU copies and mipmap generation
+pub struct BlitCommandEncoder {
+    handle: *mut IGLBlitCommandEncoder,
+}
+
+impl BlitCommandEncoder {
+    fn new(command_buffer: &CommandBuffer) -> Result<Self> {
+        let handle = unsafe { igl_command_buffer_create_blit_encoder(command_buffer.handle) };
+        if handle.is_null() {
+            return Err(Error::NullPointer);
+        }
+        Ok(BlitCommandEncoder { handle })
+    }
+
+    pub fn copy_buffer(&self, src: &Buffer, dst: &Buffer, src_offset: u32, dst_offset: u32, size: u32) {
+        unsafe {
+            igl_blit_encoder_copy_buffer(self.handle, src.as_ptr(), dst.as_ptr(), src_offset, dst_offset, size);
+        }
+    }
+
+    pub fn copy_buffer_to_texture(&self, src: &Buffer, src_offset: u32, dst: &Texture, mip_level: u32) {
+        unsafe {
+            igl_blit_encoder_copy_buffer_to_texture(self.handle, src.as_ptr(), src_offset, dst.handle, mip_level);
+        }
+    }
+
+    pub fn copy_texture_to_buffer(&self, src: &Texture, mip_level: u32, dst: &Buffer, dst_offset: u32) {
+        unsafe {
+            igl_blit_encoder_copy_texture_to_buffer(self.handle, src.handle, mip_level, dst.as_ptr(), dst_offset);
+        }
+    }
+
+    pub fn generate_mipmaps(&self, texture: &Texture) {
+        unsafe {
+            igl_blit_encoder_generate_mipmaps(self.handle, texture.handle);
+        }
+    }
+
+    pub fn end_encoding(self) {
+        unsafe {
+            igl_blit_encoder_end_encoding(self.handle);
+        }
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for BlitCommandEncoder {
+    fn drop(&mut self) {
+        if !self.handle.is_null() {
+            unsafe {
+                igl_blit_encoder_end_encoding(self.handle);
+            }
+        }
+    }
+}
@@ -18,7 +18,9 @@
 use igl_sys::*;
 use std::os::raw::c_void;
 
+pub mod drm;
 pub mod graphics;
+pub use drm::ConnectorId;
 pub use graphics::*;
 
 /// Error type for IGL operations
@@ -29,6 +31,14 @@ pub enum Error {
     InitializationFailed,
     UpdateFailed,
     NullPointer,
+    ShaderCompilationFailed(String),
+    NoBackendAvailable,
+    DrmOpenFailed,
+    NoConnector,
+    ModesetFailed,
+    DeviceLost,
+    BufferImportFailed,
+    PipelineCacheFailed,
 }
 
 impl std::fmt::Display for Error {
@@ -39,6 +49,25 @@ impl std::fmt::Display for Error {
             Error::InitializationFailed => write!(f, "Failed to initialize render session"),
             Error::UpdateFailed => write!(f, "Failed to update render session"),
             Error::NullPointer => write!(f, "Null pointer encountered"),
+            Error::ShaderCompilationFailed(log) => {
+                write!(f, "Shader compilation failed: {}", log)
+            }
+            Error::NoBackendAvailable => {
+                write!(f, "None of the requested backends initialized successfully")
+            }
+            Error::DrmOpenFailed => write!(f, "Failed to open DRM device"),
+            Error::NoConnector => write!(f, "No connected DRM connector found"),
+            Error::ModesetFailed => write!(f, "Failed to set the requested DRM display mode"),
+            Error::DeviceLost => write!(
+                f,
+                "GPU device was lost (driver reset, surprise removal, or TDR); recovery required"
+            ),
+            Error::BufferImportFailed => {
+                write!(f, "Failed to import external buffer (dma-buf or EGLImage) as a texture")
+            }
+            Error::PipelineCacheFailed => {
+                write!(f, "Failed to open, flush, or load the on-disk pipeline cache")
+            }
         }
     }
 }
@@ -47,9 +76,111 @@ impl std::error::Error for Error {}
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// A graphics backend IGL can target
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Backend {
+    Metal,
+    Vulkan,
+    OpenGl,
+    OpenGlEs,
+}
+
+impl From<Backend> for igl_sys::graphics::IGLBackendType {
+    fn from(backend: Backend) -> Self {
+        match backend {
+            Backend::Metal => igl_sys::graphics::IGLBackendType::Metal,
+            Backend::Vulkan => igl_sys::graphics::IGLBackendType::Vulkan,
+            // igl-sys has no distinct ES variant; the backend's context
+            // creation path picks GLES vs desktop GL from the platform.
+            Backend::OpenGl | Backend::OpenGlEs => igl_sys::graphics::IGLBackendType::OpenGL,
+        }
+    }
+}
+
+/// Context-reset robustness strategy to request when creating a platform,
+/// mirroring glutin's `Robustness` enum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Robustness {
+    /// No robustness negotiation; a reset silently corrupts the context.
+    NotRobust,
+    /// The driver resets the context but the application is never told,
+    /// so it must detect loss itself (e.g. via `Platform::is_device_lost`).
+    RobustNoResetNotification,
+    /// The context is lost outright on reset; subsequent calls fail until
+    /// the platform is recreated.
+    RobustLoseContextOnReset,
+}
+
+impl From<Robustness> for igl_sys::IGLRobustness {
+    fn from(robustness: Robustness) -> Self {
+        match robustness {
+            Robustness::NotRobust => igl_sys::IGLRobustness::NotRobust,
+            Robustness::RobustNoResetNotification => {
+                igl_sys::IGLRobustness::RobustNoResetNotification
+            }
+            Robustness::RobustLoseContextOnReset => {
+                igl_sys::IGLRobustness::RobustLoseContextOnReset
+            }
+        }
+    }
+}
+
+/// Extracts the native window pointer and, where the backend has one, the
+/// native display connection it belongs to (Display* on Xlib,
+/// xcb_connection_t* on XCB, wl_display* on Wayland) from any
+/// `raw-window-handle` window. Matches every supported `RawWindowHandle`
+/// variant (AppKit, Xlib, Xcb, Wayland, Win32); AppKit and Win32 have no
+/// separate display object, so their display pointer is always null.
+fn native_window_handle<W>(window: &W) -> Result<(*mut c_void, *mut c_void)>
+where
+    W: raw_window_handle::HasWindowHandle + raw_window_handle::HasDisplayHandle,
+{
+    use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
+
+    let window_handle = window
+        .window_handle()
+        .map_err(|_| Error::PlatformCreationFailed)?;
+
+    let native_window: *mut c_void = match window_handle.as_raw() {
+        RawWindowHandle::AppKit(handle) => handle.ns_view.as_ptr(),
+        RawWindowHandle::Xlib(handle) => handle.window as *mut c_void,
+        RawWindowHandle::Xcb(handle) => handle.window.get() as *mut c_void,
+        RawWindowHandle::Wayland(handle) => handle.surface.as_ptr(),
+        RawWindowHandle::Win32(handle) => handle.hwnd.get() as *mut c_void,
+        _ => return Err(Error::PlatformCreationFailed),
+    };
+
+    let display_handle = window
+        .display_handle()
+        .map_err(|_| Error::PlatformCreationFailed)?;
+
+    let native_display: *mut c_void = match display_handle.as_raw() {
+        RawDisplayHandle::Xlib(handle) => handle
+            .display
+            .map(|p| p.as_ptr())
+            .unwrap_or(std::ptr::null_mut()),
+        RawDisplayHandle::Xcb(handle) => handle
+            .connection
+            .map(|p| p.as_ptr())
+            .unwrap_or(std::ptr::null_mut()),
+        RawDisplayHandle::Wayland(handle) => handle.display.as_ptr(),
+        // AppKit/Windows backends carry everything they need in the
+        // window handle itself.
+        _ => std::ptr::null_mut(),
+    };
+
+    Ok((native_window, native_display))
+}
+
+/// Identifies one of a `Platform`'s additional outputs, as returned by
+/// `Platform::add_output`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OutputId(u32);
+
 /// Platform represents the graphics device and context
 pub struct Platform {
     handle: *mut IGLPlatform,
+    backend: Backend,
 }
 
 impl Platform {
@@ -57,14 +188,201 @@ impl Platform {
     ///
     /// # Safety
     /// The window_handle must be a valid NSView pointer
-    pub fn create_metal(window_handle: *mut c_void, width: i32, height: i32) -> Result<Self> {
+    pub unsafe fn create_metal(window_handle: *mut c_void, width: i32, height: i32) -> Result<Self> {
         let handle = unsafe { igl_platform_create_metal(window_handle, width, height) };
 
         if handle.is_null() {
             return Err(Error::PlatformCreationFailed);
         }
 
-        Ok(Platform { handle })
+        Ok(Platform {
+            handle,
+            backend: Backend::Metal,
+        })
+    }
+
+    /// Create a new Vulkan platform.
+    ///
+    /// `display_handle` is the native display connection the window came
+    /// from (Display*/xcb_connection_t*); pass null if unavailable (GLFW
+    /// and similar APIs sometimes don't expose one).
+    ///
+    /// # Safety
+    /// The window_handle must be a valid native window handle for the
+    /// current platform (HWND, xcb_window_t, wl_surface, ...), and
+    /// display_handle, if non-null, must be the display connection it was
+    /// obtained from.
+    pub unsafe fn create_vulkan(
+        window_handle: *mut c_void,
+        display_handle: *mut c_void,
+        width: i32,
+        height: i32,
+    ) -> Result<Self> {
+        let handle = unsafe { igl_platform_create_vulkan(window_handle, display_handle, width, height) };
+
+        if handle.is_null() {
+            return Err(Error::PlatformCreationFailed);
+        }
+
+        Ok(Platform {
+            handle,
+            backend: Backend::Vulkan,
+        })
+    }
+
+    /// Create a new OpenGL/OpenGL ES platform.
+    ///
+    /// `display_handle` is the native display connection the window came
+    /// from (Display*/xcb_connection_t*/wl_display*); pass null if
+    /// unavailable.
+    ///
+    /// # Safety
+    /// The window_handle must be a valid native window handle for the
+    /// current platform, and display_handle, if non-null, must be the
+    /// display connection it was obtained from.
+    pub unsafe fn create_opengl(
+        window_handle: *mut c_void,
+        display_handle: *mut c_void,
+        width: i32,
+        height: i32,
+    ) -> Result<Self> {
+        let handle = unsafe { igl_platform_create_opengl(window_handle, display_handle, width, height) };
+
+        if handle.is_null() {
+            return Err(Error::PlatformCreationFailed);
+        }
+
+        Ok(Platform {
+            handle,
+            backend: Backend::OpenGl,
+        })
+    }
+
+    /// Try each backend in `preferred`, in order, and return the platform
+    /// for the first one that initializes successfully.
+    ///
+    /// # Safety
+    /// The window_handle must be a valid native window handle for every
+    /// backend attempted.
+    pub unsafe fn create(
+        window_handle: *mut c_void,
+        width: i32,
+        height: i32,
+        preferred: &[Backend],
+    ) -> Result<Self> {
+        for &backend in preferred {
+            let result = unsafe {
+                match backend {
+                    Backend::Metal => Self::create_metal(window_handle, width, height),
+                    Backend::Vulkan => {
+                        Self::create_vulkan(window_handle, std::ptr::null_mut(), width, height)
+                    }
+                    Backend::OpenGl | Backend::OpenGlEs => {
+                        Self::create_opengl(window_handle, std::ptr::null_mut(), width, height)
+                    }
+                }
+            };
+            if let Ok(platform) = result {
+                return Ok(platform);
+            }
+        }
+        Err(Error::NoBackendAvailable)
+    }
+
+    /// The backend this platform was created with
+    pub fn backend_type(&self) -> Backend {
+        self.backend
+    }
+
+    /// Create a platform that negotiates `robustness` with the driver, so
+    /// a later driver reset (GPU TDR, surprise device removal) is reported
+    /// rather than silently corrupting rendering.
+    ///
+    /// # Safety
+    /// The window_handle must be a valid native window handle for `backend`.
+    pub unsafe fn create_with_robustness(
+        window_handle: *mut c_void,
+        width: i32,
+        height: i32,
+        backend: Backend,
+        robustness: Robustness,
+    ) -> Result<Self> {
+        let handle = unsafe {
+            igl_platform_create_with_robustness(
+                backend.into(),
+                window_handle,
+                width,
+                height,
+                robustness.into(),
+            )
+        };
+
+        if handle.is_null() {
+            return Err(Error::PlatformCreationFailed);
+        }
+
+        Ok(Platform { handle, backend })
+    }
+
+    /// Whether the GPU context behind this platform has been reset/lost.
+    /// Only meaningful if the platform was created with a `Robustness`
+    /// other than `NotRobust`.
+    pub fn is_device_lost(&self) -> bool {
+        unsafe { igl_platform_is_device_lost(self.handle) }
+    }
+
+    pub(crate) fn from_raw_handle(handle: *mut IGLPlatform, backend: Backend) -> Self {
+        Platform { handle, backend }
+    }
+
+    /// Create a platform with an offscreen swapchain and no window system
+    /// attached, for golden-image tests and headless thumbnail generation.
+    pub fn create_headless(backend: Backend, width: i32, height: i32) -> Result<Self> {
+        let handle = unsafe { igl_platform_create_headless(backend.into(), width, height) };
+
+        if handle.is_null() {
+            return Err(Error::PlatformCreationFailed);
+        }
+
+        Ok(Platform { handle, backend })
+    }
+
+    /// Read back the platform's current offscreen color attachment.
+    pub fn read_pixels(&self, byte_size: usize) -> Result<Vec<u8>> {
+        let mut data = vec![0u8; byte_size];
+        let mut size = byte_size as u32;
+        let success =
+            unsafe { igl_platform_read_pixels(self.handle, data.as_mut_ptr() as *mut c_void, &mut size) };
+        if !success {
+            return Err(Error::NullPointer);
+        }
+        data.truncate(size as usize);
+        Ok(data)
+    }
+
+    /// Create a platform from any `raw-window-handle` window, matching
+    /// every supported `RawWindowHandle` variant (AppKit, Xlib, Xcb,
+    /// Wayland, Win32) and routing to the corresponding backend
+    /// constructor. Works on macOS, Linux (X11 and Wayland), and Windows
+    /// without the caller manually unwrapping raw pointers.
+    pub fn from_window_handle<W>(window: &W, width: i32, height: i32, backend: Backend) -> Result<Self>
+    where
+        W: raw_window_handle::HasWindowHandle + raw_window_handle::HasDisplayHandle,
+    {
+        let (native_window, native_display) = native_window_handle(window)?;
+
+        // Safe: native_window_handle derived these pointers from a live
+        // `W: HasWindowHandle + HasDisplayHandle`, so they are valid for
+        // the matched backend.
+        unsafe {
+            match backend {
+                Backend::Metal => Self::create_metal(native_window, width, height),
+                Backend::Vulkan => Self::create_vulkan(native_window, native_display, width, height),
+                Backend::OpenGl | Backend::OpenGlEs => {
+                    Self::create_opengl(native_window, native_display, width, height)
+                }
+            }
+        }
     }
 
     /// Get the raw platform handle
@@ -96,7 +414,11 @@ impl Platform {
         };
 
         if !success || frame_textures.color.is_null() || frame_textures.depth.is_null() {
-            return Err(Error::NullPointer);
+            return Err(if self.is_device_lost() {
+                Error::DeviceLost
+            } else {
+                Error::NullPointer
+            });
         }
 
         Ok((
@@ -111,6 +433,67 @@ impl Platform {
             igl_platform_present_frame(self.handle);
         }
     }
+
+    /// Add another output (an additional window or monitor) to this
+    /// platform, with its own swapchain, so the same `Platform`/`Device`
+    /// can drive several windows at once. There is no call to resize an
+    /// added output; `resize` takes no `OutputId`.
+    pub fn add_output<W>(&self, window: &W, width: i32, height: i32) -> Result<OutputId>
+    where
+        W: raw_window_handle::HasWindowHandle + raw_window_handle::HasDisplayHandle,
+    {
+        let (native_window, native_display) = native_window_handle(window)?;
+
+        let output_id = unsafe {
+            igl_platform_add_output(self.handle, native_window, native_display, width, height)
+        };
+
+        if output_id < 0 {
+            return Err(Error::PlatformCreationFailed);
+        }
+
+        Ok(OutputId(output_id as u32))
+    }
+
+    /// Remove a previously added output and destroy its swapchain.
+    pub fn remove_output(&self, output: OutputId) {
+        unsafe {
+            igl_platform_remove_output(self.handle, output.0);
+        }
+    }
+
+    /// Get textures for the current frame of a specific output (acquires
+    /// that output's drawable).
+    pub fn get_frame_textures_for(&self, output: OutputId) -> Result<(Texture, Texture)> {
+        let mut frame_textures = IGLFrameTextures {
+            color: std::ptr::null_mut(),
+            depth: std::ptr::null_mut(),
+        };
+
+        let success = unsafe {
+            igl_platform_get_frame_textures_for(self.handle, output.0, &mut frame_textures)
+        };
+
+        if !success || frame_textures.color.is_null() || frame_textures.depth.is_null() {
+            return Err(if self.is_device_lost() {
+                Error::DeviceLost
+            } else {
+                Error::NullPointer
+            });
+        }
+
+        Ok((
+            Texture::from_raw(frame_textures.color),
+            Texture::from_raw(frame_textures.depth),
+        ))
+    }
+
+    /// Present the current frame of a specific output.
+    pub fn present_output(&self, output: OutputId) {
+        unsafe {
+            igl_platform_present_output(self.handle, output.0);
+        }
+    }
 }
 
 impl Drop for Platform {
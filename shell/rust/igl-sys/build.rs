@@ -12,32 +12,39 @@ fn main() {
         .parent()
         .unwrap();
 
+    // cargo sets PROFILE to "debug" or "release"; IGL's own build places
+    // artifacts in directories named "Debug"/"Release" to match the CMake
+    // multi-config generators used on the other platforms.
+    let profile_dir = match env::var("PROFILE").as_deref() {
+        Ok("release") => "Release",
+        _ => "Debug",
+    };
+
     let build_dir = project_root.join("build");
 
     // Tell cargo to look for libraries in the build directory
-    println!("cargo:rustc-link-search=native={}/shell/rust/igl-c-wrapper/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/shell/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/shell/mac/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/src/igl/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/src/igl/metal/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/src/igl/opengl/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/src/igl/glslang/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/src/igl/glslang/glslang/SPIRV/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/src/igl/glslang/glslang/glslang/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/IGLU/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/IGLU/SPIRV-Cross/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/fmt/Debug", build_dir.display());
-    println!("cargo:rustc-link-search=native={}/Debug", build_dir.display());
+    println!("cargo:rustc-link-search=native={}/shell/rust/igl-c-wrapper/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/shell/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/shell/mac/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/src/igl/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/src/igl/metal/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/src/igl/opengl/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/src/igl/vulkan/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/src/igl/glslang/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/src/igl/glslang/glslang/SPIRV/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/src/igl/glslang/glslang/glslang/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/IGLU/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/IGLU/SPIRV-Cross/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/fmt/{}", build_dir.display(), profile_dir);
+    println!("cargo:rustc-link-search=native={}/{}", build_dir.display(), profile_dir);
 
     // Link the C wrapper library
     println!("cargo:rustc-link-lib=static=igl_c_wrapper");
 
-    // Link IGL libraries
+    // Link IGL libraries that are always built regardless of backend
     println!("cargo:rustc-link-lib=static=IGLShellShared");
     println!("cargo:rustc-link-lib=static=IGLShellPlatform");
     println!("cargo:rustc-link-lib=static=IGLLibrary");
-    println!("cargo:rustc-link-lib=static=IGLMetal");
-    println!("cargo:rustc-link-lib=static=IGLOpenGL");
     println!("cargo:rustc-link-lib=static=IGLGlslang");
     println!("cargo:rustc-link-lib=static=SPIRV");
     println!("cargo:rustc-link-lib=static=glslang");
@@ -55,20 +62,66 @@ fn main() {
     println!("cargo:rustc-link-lib=static=IGLUtexture_loader");
     println!("cargo:rustc-link-lib=static=IGLUuniform");
     println!("cargo:rustc-link-lib=static=IGLstb");
-    println!("cargo:rustc-link-lib=static=fmtd");
 
-    // Link system frameworks
-    println!("cargo:rustc-link-lib=framework=Metal");
-    println!("cargo:rustc-link-lib=framework=MetalKit");
-    println!("cargo:rustc-link-lib=framework=AppKit");
-    println!("cargo:rustc-link-lib=framework=QuartzCore");
-    println!("cargo:rustc-link-lib=framework=CoreGraphics");
-    println!("cargo:rustc-link-lib=framework=Foundation");
+    // fmt's CMake build appends a "d" postfix to the debug variant of the
+    // library name, same as the directory split above.
+    let fmt_lib = match profile_dir {
+        "Debug" => "fmtd",
+        _ => "fmt",
+    };
+    println!("cargo:rustc-link-lib=static={}", fmt_lib);
+
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+
+    // Backend libraries and the frameworks/loaders each backend needs are
+    // gated behind cargo features so a Linux/Windows build doesn't need to pull in
+    // Apple-only frameworks, and a headless build doesn't need any GPU
+    // loader at all.
+    if cfg!(feature = "metal") {
+        println!("cargo:rustc-link-lib=static=IGLMetal");
+    }
+    if cfg!(feature = "opengl") {
+        println!("cargo:rustc-link-lib=static=IGLOpenGL");
+    }
+    if cfg!(feature = "vulkan") {
+        println!("cargo:rustc-link-lib=static=IGLVulkan");
+    }
 
-    // Link C++ standard library
-    println!("cargo:rustc-link-lib=c++");
+    match target_os.as_str() {
+        "macos" => {
+            if cfg!(feature = "metal") {
+                println!("cargo:rustc-link-lib=framework=Metal");
+                println!("cargo:rustc-link-lib=framework=MetalKit");
+                println!("cargo:rustc-link-lib=framework=QuartzCore");
+            }
+            println!("cargo:rustc-link-lib=framework=AppKit");
+            println!("cargo:rustc-link-lib=framework=CoreGraphics");
+            println!("cargo:rustc-link-lib=framework=Foundation");
+            println!("cargo:rustc-link-lib=c++");
+        }
+        "linux" => {
+            if cfg!(feature = "vulkan") {
+                println!("cargo:rustc-link-lib=dylib=vulkan");
+            }
+            if cfg!(feature = "opengl") {
+                println!("cargo:rustc-link-lib=dylib=EGL");
+                println!("cargo:rustc-link-lib=dylib=GL");
+            }
+            println!("cargo:rustc-link-lib=dylib=stdc++");
+        }
+        "windows" => {
+            if cfg!(feature = "vulkan") {
+                println!("cargo:rustc-link-lib=dylib=vulkan-1");
+            }
+            if cfg!(feature = "opengl") {
+                println!("cargo:rustc-link-lib=dylib=opengl32");
+            }
+        }
+        _ => {}
+    }
 
     // Rebuild if the C wrapper changes
     println!("cargo:rerun-if-changed=../igl-c-wrapper/include/igl_c_wrapper.h");
     println!("cargo:rerun-if-changed=../igl-c-wrapper/src/igl_c_wrapper.cpp");
+    println!("cargo:rerun-if-env-changed=PROFILE");
 }
@@ -0,0 +1,40 @@
+//! Low-level FFI bindings for the direct DRM/KMS + GBM display backend
+
+use std::os::raw::{c_char, c_int, c_uint};
+
+use crate::IGLPlatform;
+
+// Why igl_platform_create_drm failed, written to *out_error whenever it
+// returns null. A null return alone can't distinguish "couldn't open the
+// device node" from "no connector plugged in" from "found a connector but
+// couldn't set the requested mode".
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLDrmError {
+    None = 0,
+    OpenFailed = 1,
+    NoConnector = 2,
+    ModesetFailed = 3,
+}
+
+extern "C" {
+    // Opens the DRM device at `card_path` (e.g. "/dev/dri/card0"),
+    // enumerates its connectors/encoders/CRTCs, picks a mode for
+    // `connector_id` (or the first connected connector if negative),
+    // creates a GBM surface, and binds it as the IGL swapchain. On failure
+    // returns null and writes the reason to *out_error.
+    pub fn igl_platform_create_drm(
+        card_path: *const c_char,
+        connector_id: c_int,
+        out_error: *mut IGLDrmError,
+    ) -> *mut IGLPlatform;
+
+    // Lists the connector IDs exposed by the DRM device at `card_path`.
+    // Pass a null `out_connectors` to size the buffer via `out_count`
+    // first, same convention as igl_texture_read_pixels.
+    pub fn igl_platform_list_drm_connectors(
+        card_path: *const c_char,
+        out_connectors: *mut c_uint,
+        out_count: *mut c_uint,
+    ) -> bool;
+}
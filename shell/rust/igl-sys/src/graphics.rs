@@ -53,6 +53,36 @@ pub struct IGLRenderPipelineState {
     _private: [u8; 0],
 }
 
+#[repr(C)]
+pub struct IGLComputePipelineState {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct IGLComputeCommandEncoder {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct IGLSamplerState {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct IGLPipelineCache {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct IGLDepthStencilState {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+pub struct IGLBlitCommandEncoder {
+    _private: [u8; 0],
+}
+
 // Enums
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -102,6 +132,13 @@ pub enum IGLWindingMode {
     CounterClockwise = 1,
 }
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLVertexInputRate {
+    Vertex = 0,
+    Instance = 1,
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum IGLLoadAction {
@@ -117,6 +154,119 @@ pub enum IGLStoreAction {
     Store = 1,
 }
 
+// Bitmask of how a texture will be used, OR'd together (e.g. Sampled |
+// Attachment for an offscreen render target that's later read back as a
+// shader input). Not an enum: the bits are combinable, not exclusive.
+pub type IGLTextureUsage = c_uint;
+pub const IGL_TEXTURE_USAGE_SAMPLED: IGLTextureUsage = 1;
+pub const IGL_TEXTURE_USAGE_ATTACHMENT: IGLTextureUsage = 2;
+pub const IGL_TEXTURE_USAGE_STORAGE: IGLTextureUsage = 4;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLSamplerFilter {
+    Nearest = 0,
+    Linear = 1,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLSamplerWrapMode {
+    Repeat = 0,
+    Clamp = 1,
+    MirrorRepeat = 2,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IGLSamplerDescriptor {
+    pub min_filter: IGLSamplerFilter,
+    pub mag_filter: IGLSamplerFilter,
+    pub mip_filter: IGLSamplerFilter,
+    pub wrap_u: IGLSamplerWrapMode,
+    pub wrap_v: IGLSamplerWrapMode,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLCompareFunction {
+    Never = 0,
+    Less = 1,
+    LessEqual = 2,
+    Greater = 3,
+    Equal = 4,
+    Always = 5,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLBlendFactor {
+    Zero = 0,
+    One = 1,
+    SrcColor = 2,
+    OneMinusSrcColor = 3,
+    SrcAlpha = 4,
+    OneMinusSrcAlpha = 5,
+    DstColor = 6,
+    OneMinusDstColor = 7,
+    DstAlpha = 8,
+    OneMinusDstAlpha = 9,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLBlendOp {
+    Add = 0,
+    Subtract = 1,
+    ReverseSubtract = 2,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLStencilOperation {
+    Keep = 0,
+    Zero = 1,
+    Replace = 2,
+    IncrementClamp = 3,
+    DecrementClamp = 4,
+    Invert = 5,
+    IncrementWrap = 6,
+    DecrementWrap = 7,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IGLStencilFaceDescriptor {
+    pub stencil_fail_op: IGLStencilOperation,
+    pub depth_fail_op: IGLStencilOperation,
+    pub pass_op: IGLStencilOperation,
+    pub compare_function: IGLCompareFunction,
+    pub read_mask: c_uint,
+    pub write_mask: c_uint,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IGLDepthStencilDescriptor {
+    pub compare_function: IGLCompareFunction,
+    pub depth_write_enabled: bool,
+    pub stencil_enabled: bool,
+    pub front: IGLStencilFaceDescriptor,
+    pub back: IGLStencilFaceDescriptor,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IGLBlendDescriptor {
+    pub enabled: bool,
+    pub src_color_factor: IGLBlendFactor,
+    pub dst_color_factor: IGLBlendFactor,
+    pub color_op: IGLBlendOp,
+    pub src_alpha_factor: IGLBlendFactor,
+    pub dst_alpha_factor: IGLBlendFactor,
+    pub alpha_op: IGLBlendOp,
+}
+
 // Structs
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -135,12 +285,18 @@ pub struct IGLVertexAttribute {
     pub offset: c_uint,
     pub name: *const c_char,
     pub location: c_int,
+    // Per-binding input_rate (IGLVertexBinding::input_rate) sets the rate
+    // for every attribute sourced from that binding; this lets a single
+    // attribute override it, for backends/layouts that key step rate per
+    // attribute rather than per buffer binding.
+    pub input_rate: IGLVertexInputRate,
 }
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct IGLVertexBinding {
     pub stride: c_uint,
+    pub input_rate: IGLVertexInputRate,
 }
 
 #[repr(C)]
@@ -163,6 +319,34 @@ extern "C" {
     pub fn igl_platform_get_device(platform: *mut c_void) -> *mut IGLDevice;
     pub fn igl_device_get_backend_type(device: *mut IGLDevice) -> IGLBackendType;
 
+    // Creates a device with an offscreen swapchain (or no swapchain at
+    // all) and no platform/window handle, for tests, CI, and server-side
+    // rendering. Unlike devices obtained from a Platform, a headless
+    // device is owned by the caller and must be released with
+    // igl_device_destroy.
+    pub fn igl_device_create_headless(backend_type: IGLBackendType) -> *mut IGLDevice;
+    pub fn igl_device_destroy(device: *mut IGLDevice);
+
+    // Offscreen color targets usable as a framebuffer attachment without a
+    // platform-provided drawable.
+    pub fn igl_device_create_offscreen_texture(
+        device: *mut IGLDevice,
+        format: c_uint,
+        width: c_uint,
+        height: c_uint,
+    ) -> *mut IGLTexture;
+
+    // Reads back the contents of a texture's given mip level into a
+    // caller-provided buffer. Returns false (leaving *out_data_size
+    // untouched) if the buffer is too small; callers should query with a
+    // null buffer first to size it.
+    pub fn igl_texture_read_pixels(
+        texture: *mut IGLTexture,
+        mip_level: c_uint,
+        out_data: *mut c_void,
+        out_data_size: *mut c_uint,
+    ) -> bool;
+
     // Command Queue
     pub fn igl_device_create_command_queue(device: *mut IGLDevice) -> *mut IGLCommandQueue;
     pub fn igl_command_queue_destroy(queue: *mut IGLCommandQueue);
@@ -189,8 +373,49 @@ extern "C" {
         vertex_entry: *const c_char,
         fragment_entry: *const c_char,
     ) -> *mut IGLShaderStages;
+
+    // Compute-only shader stages, holding a single kernel function rather
+    // than a vertex/fragment pair.
+    pub fn igl_device_create_shader_stages_compute_metal(
+        device: *mut IGLDevice,
+        source: *const c_char,
+        kernel_entry: *const c_char,
+    ) -> *mut IGLShaderStages;
+
+    // Cross-compiles GLSL to the active backend's shading language (MSL for
+    // Metal, GLSL/ESSL for OpenGL) via glslang + spirv-cross before handing
+    // it to the backend. On failure, writes the glslang/spirv-cross
+    // diagnostic log to *out_error_log (caller must free with
+    // igl_free_error_log) and returns null.
+    pub fn igl_device_create_shader_stages_from_glsl(
+        device: *mut IGLDevice,
+        vertex_source: *const c_char,
+        fragment_source: *const c_char,
+        vertex_entry: *const c_char,
+        fragment_entry: *const c_char,
+        out_error_log: *mut *mut c_char,
+    ) -> *mut IGLShaderStages;
+
+    // Cross-compiles pre-built SPIR-V binaries to the active backend's
+    // shading language. Same error-log contract as
+    // igl_device_create_shader_stages_from_glsl.
+    pub fn igl_device_create_shader_stages_from_spirv(
+        device: *mut IGLDevice,
+        vertex_spirv: *const u32,
+        vertex_spirv_len: c_uint,
+        fragment_spirv: *const u32,
+        fragment_spirv_len: c_uint,
+        vertex_entry: *const c_char,
+        fragment_entry: *const c_char,
+        out_error_log: *mut *mut c_char,
+    ) -> *mut IGLShaderStages;
+
     pub fn igl_shader_stages_destroy(stages: *mut IGLShaderStages);
 
+    // Frees a diagnostic log produced by igl_device_create_shader_stages_from_glsl
+    // or igl_device_create_shader_stages_from_spirv.
+    pub fn igl_free_error_log(log: *mut c_char);
+
     // Vertex Input State
     pub fn igl_device_create_vertex_input_state(
         device: *mut IGLDevice,
@@ -212,6 +437,7 @@ extern "C" {
     pub fn igl_framebuffer_get_color_attachment(framebuffer: *mut IGLFramebuffer) -> *mut IGLTexture;
 
     // Render Pipeline State
+    #[allow(clippy::too_many_arguments)]
     pub fn igl_device_create_render_pipeline(
         device: *mut IGLDevice,
         vertex_input: *mut IGLVertexInputState,
@@ -220,9 +446,21 @@ extern "C" {
         depth_attachment_format: c_uint,
         cull_mode: IGLCullMode,
         winding_mode: IGLWindingMode,
+        blend: *const IGLBlendDescriptor,
     ) -> *mut IGLRenderPipelineState;
     pub fn igl_render_pipeline_state_destroy(pipeline: *mut IGLRenderPipelineState);
 
+    // Depth-Stencil State
+    pub fn igl_device_create_depth_stencil_state(
+        device: *mut IGLDevice,
+        descriptor: *const IGLDepthStencilDescriptor,
+    ) -> *mut IGLDepthStencilState;
+    pub fn igl_depth_stencil_state_destroy(state: *mut IGLDepthStencilState);
+    pub fn igl_render_encoder_bind_depth_stencil_state(
+        encoder: *mut IGLRenderCommandEncoder,
+        state: *mut IGLDepthStencilState,
+    );
+
     // Render Command Encoder
     pub fn igl_command_buffer_create_render_encoder(
         buffer: *mut IGLCommandBuffer,
@@ -251,8 +489,179 @@ extern "C" {
         buffer: *mut IGLBuffer,
     );
     pub fn igl_render_encoder_draw_indexed(encoder: *mut IGLRenderCommandEncoder, index_count: c_uint);
+    pub fn igl_render_encoder_draw_indexed_instanced(
+        encoder: *mut IGLRenderCommandEncoder,
+        index_count: c_uint,
+        instance_count: c_uint,
+        first_index: c_uint,
+        base_vertex: c_int,
+        base_instance: c_uint,
+    );
+    pub fn igl_render_encoder_draw_indexed_indirect(
+        encoder: *mut IGLRenderCommandEncoder,
+        indirect_buffer: *mut IGLBuffer,
+        indirect_buffer_offset: c_uint,
+    );
+
+    // Texture creation and upload
+    pub fn igl_device_create_texture_2d(
+        device: *mut IGLDevice,
+        format: c_uint,
+        width: c_uint,
+        height: c_uint,
+        usage: IGLTextureUsage,
+        mip_count: c_uint,
+    ) -> *mut IGLTexture;
+    pub fn igl_texture_upload(
+        texture: *mut IGLTexture,
+        mip_level: c_uint,
+        data: *const c_void,
+        size: c_uint,
+    ) -> bool;
+    pub fn igl_texture_destroy(texture: *mut IGLTexture);
+
+    // Zero-copy external texture import
+    //
+    // Wraps an externally-allocated dma-buf (one fd per plane, e.g. from
+    // a V4L2 capture device, a Wayland compositor, or another process) as
+    // a texture with no host-side copy, binding it through
+    // EGL_EXT_image_dma_buf_import(_modifiers) on GL/Vulkan external
+    // memory on Vulkan.
+    pub fn igl_device_import_dmabuf_texture(
+        device: *mut IGLDevice,
+        fds: *const c_int,
+        strides: *const c_uint,
+        offsets: *const c_uint,
+        modifiers: *const u64,
+        plane_count: c_uint,
+        fourcc: c_uint,
+        width: c_uint,
+        height: c_uint,
+    ) -> *mut IGLTexture;
+
+    // Wraps an existing EGLImage (e.g. from another GL/EGL context, or a
+    // platform media-decode surface) as a texture with no host-side copy.
+    pub fn igl_device_import_egl_image_texture(
+        device: *mut IGLDevice,
+        egl_image: *mut c_void,
+        width: c_uint,
+        height: c_uint,
+    ) -> *mut IGLTexture;
+
+    // Lists the DRM fourcc formats `igl_device_import_dmabuf_texture`
+    // accepts on this device. Pass a null `out_formats` to size the
+    // buffer via `out_count` first, same convention as
+    // igl_texture_read_pixels.
+    pub fn igl_device_supported_dmabuf_formats(
+        device: *mut IGLDevice,
+        out_formats: *mut c_uint,
+        out_count: *mut c_uint,
+    ) -> bool;
+
+    // Sampler state
+    pub fn igl_device_create_sampler(
+        device: *mut IGLDevice,
+        descriptor: *const IGLSamplerDescriptor,
+    ) -> *mut IGLSamplerState;
+    pub fn igl_sampler_state_destroy(sampler: *mut IGLSamplerState);
+
+    // Texture/sampler binding
+    pub fn igl_render_encoder_bind_texture(
+        encoder: *mut IGLRenderCommandEncoder,
+        index: c_uint,
+        texture: *mut IGLTexture,
+    );
+    pub fn igl_render_encoder_bind_sampler(
+        encoder: *mut IGLRenderCommandEncoder,
+        index: c_uint,
+        sampler: *mut IGLSamplerState,
+    );
 
     // Texture helpers
     pub fn igl_texture_get_format(texture: *mut IGLTexture) -> c_uint;
     pub fn igl_texture_get_aspect_ratio(texture: *mut IGLTexture) -> c_float;
+    pub fn igl_texture_get_width(texture: *mut IGLTexture) -> c_uint;
+    pub fn igl_texture_get_height(texture: *mut IGLTexture) -> c_uint;
+
+    // Pipeline Cache
+    //
+    // Opens (creating if needed) an on-disk pipeline/shader cache at
+    // `path`. Once created, igl_device_create_render_pipeline and the
+    // igl_device_create_shader_stages_* family transparently hash their
+    // inputs (shader source/SPIR-V bytes plus descriptor fields) and
+    // consult the cache before compiling, writing the result back on a
+    // miss. Wraps the backend's own cache-aware entry point (a Metal
+    // binary archive, a Vulkan pipeline cache, or a GL program binary).
+    pub fn igl_device_create_pipeline_cache(
+        device: *mut IGLDevice,
+        path: *const c_char,
+    ) -> *mut IGLPipelineCache;
+    pub fn igl_pipeline_cache_flush(cache: *mut IGLPipelineCache) -> bool;
+    pub fn igl_pipeline_cache_load(cache: *mut IGLPipelineCache) -> bool;
+    pub fn igl_pipeline_cache_destroy(cache: *mut IGLPipelineCache);
+
+    // Compute Pipeline State
+    pub fn igl_device_create_compute_pipeline(
+        device: *mut IGLDevice,
+        shaders: *mut IGLShaderStages,
+    ) -> *mut IGLComputePipelineState;
+    pub fn igl_compute_pipeline_state_destroy(pipeline: *mut IGLComputePipelineState);
+
+    // Compute Command Encoder
+    pub fn igl_command_buffer_create_compute_encoder(
+        buffer: *mut IGLCommandBuffer,
+    ) -> *mut IGLComputeCommandEncoder;
+    pub fn igl_compute_encoder_bind_pipeline(
+        encoder: *mut IGLComputeCommandEncoder,
+        pipeline: *mut IGLComputePipelineState,
+    );
+    pub fn igl_compute_encoder_bind_buffer(
+        encoder: *mut IGLComputeCommandEncoder,
+        index: c_uint,
+        buffer: *mut IGLBuffer,
+    );
+    pub fn igl_compute_encoder_bind_texture(
+        encoder: *mut IGLComputeCommandEncoder,
+        index: c_uint,
+        texture: *mut IGLTexture,
+    );
+    pub fn igl_compute_encoder_dispatch(
+        encoder: *mut IGLComputeCommandEncoder,
+        threadgroups_x: c_uint,
+        threadgroups_y: c_uint,
+        threadgroups_z: c_uint,
+        threads_per_group_x: c_uint,
+        threads_per_group_y: c_uint,
+        threads_per_group_z: c_uint,
+    );
+    pub fn igl_compute_encoder_end_encoding(encoder: *mut IGLComputeCommandEncoder);
+
+    // Blit Command Encoder
+    pub fn igl_command_buffer_create_blit_encoder(
+        buffer: *mut IGLCommandBuffer,
+    ) -> *mut IGLBlitCommandEncoder;
+    pub fn igl_blit_encoder_copy_buffer(
+        encoder: *mut IGLBlitCommandEncoder,
+        src: *mut IGLBuffer,
+        dst: *mut IGLBuffer,
+        src_offset: c_uint,
+        dst_offset: c_uint,
+        size: c_uint,
+    );
+    pub fn igl_blit_encoder_copy_buffer_to_texture(
+        encoder: *mut IGLBlitCommandEncoder,
+        src: *mut IGLBuffer,
+        src_offset: c_uint,
+        dst: *mut IGLTexture,
+        mip_level: c_uint,
+    );
+    pub fn igl_blit_encoder_copy_texture_to_buffer(
+        encoder: *mut IGLBlitCommandEncoder,
+        src: *mut IGLTexture,
+        mip_level: c_uint,
+        dst: *mut IGLBuffer,
+        dst_offset: c_uint,
+    );
+    pub fn igl_blit_encoder_generate_mipmaps(encoder: *mut IGLBlitCommandEncoder, texture: *mut IGLTexture);
+    pub fn igl_blit_encoder_end_encoding(encoder: *mut IGLBlitCommandEncoder);
 }
@@ -7,8 +7,9 @@
 #![allow(non_snake_case)]
 #![allow(dead_code)]
 
-use std::os::raw::{c_int, c_void};
+use std::os::raw::{c_int, c_uint, c_void};
 
+pub mod drm;
 pub mod graphics;
 
 // Opaque types
@@ -24,6 +25,23 @@ pub struct IGLRenderSession {
 
 pub type IGLNativeWindowHandle = *mut c_void;
 
+// The platform display connection a window handle was obtained from
+// (Display* on Xlib, xcb_connection_t* on XCB, wl_display* on Wayland).
+// Null where the backend has no separate display object to bind
+// (Metal/AppKit, Win32).
+pub type IGLNativeDisplayHandle = *mut c_void;
+
+// Context-reset robustness strategy to negotiate with the driver at
+// platform creation, mirroring EGL_EXT_create_context_robustness /
+// WGL_ARB_create_context_robustness.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IGLRobustness {
+    NotRobust = 0,
+    RobustNoResetNotification = 1,
+    RobustLoseContextOnReset = 2,
+}
+
 #[repr(C)]
 pub struct IGLSurfaceTextures {
     pub color_texture: *mut c_void,
@@ -38,8 +56,60 @@ extern "C" {
         height: c_int,
     ) -> *mut IGLPlatform;
 
+    // `display_handle` is the native display connection the window was
+    // obtained from (Display*/xcb_connection_t*/wl_display*); pass null
+    // when the backend has none (e.g. no display info available).
+    pub fn igl_platform_create_vulkan(
+        window_handle: IGLNativeWindowHandle,
+        display_handle: IGLNativeDisplayHandle,
+        width: c_int,
+        height: c_int,
+    ) -> *mut IGLPlatform;
+
+    pub fn igl_platform_create_opengl(
+        window_handle: IGLNativeWindowHandle,
+        display_handle: IGLNativeDisplayHandle,
+        width: c_int,
+        height: c_int,
+    ) -> *mut IGLPlatform;
+
+    // Brings up a surfaceless/offscreen context with no window system
+    // attached (EGL surfaceless or pbuffer-style on GL, a headless
+    // swapchain-free device on Metal/Vulkan), for CI and server-side
+    // rendering.
+    pub fn igl_platform_create_headless(
+        backend_type: graphics::IGLBackendType,
+        width: c_int,
+        height: c_int,
+    ) -> *mut IGLPlatform;
+
+    // Reads back the platform's current offscreen color attachment.
+    // Callers should query with a null buffer first to size it, as with
+    // igl_texture_read_pixels.
+    pub fn igl_platform_read_pixels(
+        platform: *mut IGLPlatform,
+        out_data: *mut c_void,
+        out_data_size: *mut c_uint,
+    ) -> bool;
+
     pub fn igl_platform_destroy(platform: *mut IGLPlatform);
 
+    // Like igl_platform_create_metal/vulkan/opengl, but negotiates the
+    // given context-reset robustness strategy with the driver up front.
+    pub fn igl_platform_create_with_robustness(
+        backend_type: graphics::IGLBackendType,
+        window_handle: IGLNativeWindowHandle,
+        width: c_int,
+        height: c_int,
+        robustness: IGLRobustness,
+    ) -> *mut IGLPlatform;
+
+    // Returns true once the GPU context behind this platform has been
+    // reset/lost and can no longer accept work; only meaningful if the
+    // platform was created with a robustness strategy other than
+    // IGLRobustness::NotRobust.
+    pub fn igl_platform_is_device_lost(platform: *mut IGLPlatform) -> bool;
+
     // RenderSession creation/destruction
     pub fn igl_render_session_create(platform: *mut IGLPlatform) -> *mut IGLRenderSession;
 
@@ -79,3 +149,31 @@ extern "C" {
 
     pub fn igl_platform_present_frame(platform: *mut IGLPlatform);
 }
+
+// Multiple simultaneous outputs
+//
+// A Platform normally owns exactly one color+depth swapchain, acquired
+// through igl_platform_get_frame_textures. These entry points let it
+// manage several independent swapchains at once, one per window/monitor.
+extern "C" {
+    // Adds a new output targeting `window_handle` (on the connection
+    // `display_handle`, or null if the backend has none) and returns its
+    // non-negative output id, or -1 on failure.
+    pub fn igl_platform_add_output(
+        platform: *mut IGLPlatform,
+        window_handle: IGLNativeWindowHandle,
+        display_handle: IGLNativeDisplayHandle,
+        width: c_int,
+        height: c_int,
+    ) -> c_int;
+
+    pub fn igl_platform_remove_output(platform: *mut IGLPlatform, output_id: c_uint);
+
+    pub fn igl_platform_get_frame_textures_for(
+        platform: *mut IGLPlatform,
+        output_id: c_uint,
+        out_textures: *mut IGLFrameTextures,
+    ) -> bool;
+
+    pub fn igl_platform_present_output(platform: *mut IGLPlatform, output_id: c_uint);
+}
@@ -6,8 +6,7 @@
 
 mod render_session;
 
-use igl_rs::Platform;
-use raw_window_handle::HasWindowHandle;
+use igl_rs::{Backend, Platform};
 use render_session::ThreeCubesRenderSession;
 use winit::{
     event::{Event, WindowEvent},
@@ -30,25 +29,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let window_size = window.inner_size();
     println!("Window size: {}x{}", window_size.width, window_size.height);
 
-    // Get native window handle for macOS
-    let window_handle = match window.window_handle()?.as_raw() {
-        raw_window_handle::RawWindowHandle::AppKit(handle) => {
-            println!("Got AppKit window handle");
-            handle.ns_view.as_ptr() as *mut std::ffi::c_void
-        }
-        _ => {
-            return Err("Unsupported platform - only macOS is supported".into());
-        }
-    };
-
-    // Create IGL platform
-    println!("Creating IGL Metal platform...");
-    let platform = Platform::create_metal(
-        window_handle,
+    // Create the IGL platform from the winit window, matching whichever
+    // native window handle variant is available on this OS.
+    println!("Creating IGL platform...");
+    let platform = Platform::from_window_handle(
+        &window,
         window_size.width as i32,
         window_size.height as i32,
+        Backend::Metal,
     )?;
-    println!("Platform created successfully");
+    println!("Platform created successfully: backend = {:?}", platform.backend_type());
 
     // Get device
     println!("Getting graphics device...");
@@ -72,6 +72,9 @@ pub struct ThreeCubesRenderSession {
     shader_stages: ShaderStages,
     pipeline_state: Option<RenderPipelineState>,
     framebuffer: Option<Framebuffer>,
+    // Lazily created by `render_to_texture` to pair with the caller's
+    // offscreen color target.
+    offscreen_depth_texture: Option<Texture>,
 
     // Cube data
     cubes: [CubeTransform; 3],
@@ -81,80 +84,100 @@ pub struct ThreeCubesRenderSession {
     last_frame_time: Instant,
 }
 
-impl ThreeCubesRenderSession {
-    pub fn new(device: &Device) -> Result<Self> {
-        // Create vertex data for a cube
-        let half = 1.0f32;
-        let vertex_data = [
-            // Front face (red tint)
-            VertexPosColor { position: [-half, half, -half], color: [1.0, 0.3, 0.3] },
-            VertexPosColor { position: [half, half, -half], color: [1.0, 0.3, 0.3] },
-            VertexPosColor { position: [-half, -half, -half], color: [0.8, 0.2, 0.2] },
-            VertexPosColor { position: [half, -half, -half], color: [0.8, 0.2, 0.2] },
-            // Back face (blue tint)
-            VertexPosColor { position: [half, half, half], color: [0.3, 0.3, 1.0] },
-            VertexPosColor { position: [-half, half, half], color: [0.3, 0.3, 1.0] },
-            VertexPosColor { position: [half, -half, half], color: [0.2, 0.2, 0.8] },
-            VertexPosColor { position: [-half, -half, half], color: [0.2, 0.2, 0.8] },
-        ];
-
-        // Index data for cube (36 indices for 12 triangles)
-        let index_data: [u16; 36] = [
-            0, 1, 2, 1, 3, 2, // front
-            1, 4, 3, 4, 6, 3, // right
-            4, 5, 6, 5, 7, 6, // back
-            5, 0, 7, 0, 2, 7, // left
-            5, 4, 0, 4, 1, 0, // top
-            2, 3, 7, 3, 6, 7, // bottom
-        ];
-
-        // Create buffers
-        let vertex_bytes = unsafe {
-            std::slice::from_raw_parts(
-                vertex_data.as_ptr() as *const u8,
-                mem::size_of_val(&vertex_data),
-            )
-        };
-        let vertex_buffer = device.create_buffer(BufferType::Vertex, vertex_bytes)?;
-
-        let index_bytes = unsafe {
-            std::slice::from_raw_parts(
-                index_data.as_ptr() as *const u8,
-                mem::size_of_val(&index_data),
-            )
-        };
-        let index_buffer = device.create_buffer(BufferType::Index, index_bytes)?;
-
-        // Create vertex input state
-        let attributes = vec![
-            VertexAttribute {
-                buffer_index: 0,
-                format: VertexFormat::Float3,
-                offset: 0,
-                name: "position".to_string(),
-                location: 0,
-            },
-            VertexAttribute {
-                buffer_index: 0,
-                format: VertexFormat::Float3,
-                offset: mem::size_of::<[f32; 3]>() as u32,
-                name: "color_in".to_string(),
-                location: 1,
-            },
-        ];
+// GPU resources that depend on a live `Device` and must be rebuilt from
+// scratch if the device is lost (see `ThreeCubesRenderSession::recover`).
+struct GpuResources {
+    vertex_buffer: Buffer,
+    index_buffer: Buffer,
+    vertex_input_state: VertexInputState,
+    shader_stages: ShaderStages,
+}
 
-        let bindings = vec![VertexBinding {
-            stride: mem::size_of::<VertexPosColor>() as u32,
-        }];
+fn create_gpu_resources(device: &Device) -> Result<GpuResources> {
+    // Create vertex data for a cube
+    let half = 1.0f32;
+    let vertex_data = [
+        // Front face (red tint)
+        VertexPosColor { position: [-half, half, -half], color: [1.0, 0.3, 0.3] },
+        VertexPosColor { position: [half, half, -half], color: [1.0, 0.3, 0.3] },
+        VertexPosColor { position: [-half, -half, -half], color: [0.8, 0.2, 0.2] },
+        VertexPosColor { position: [half, -half, -half], color: [0.8, 0.2, 0.2] },
+        // Back face (blue tint)
+        VertexPosColor { position: [half, half, half], color: [0.3, 0.3, 1.0] },
+        VertexPosColor { position: [-half, half, half], color: [0.3, 0.3, 1.0] },
+        VertexPosColor { position: [half, -half, half], color: [0.2, 0.2, 0.8] },
+        VertexPosColor { position: [-half, -half, half], color: [0.2, 0.2, 0.8] },
+    ];
+
+    // Index data for cube (36 indices for 12 triangles)
+    let index_data: [u16; 36] = [
+        0, 1, 2, 1, 3, 2, // front
+        1, 4, 3, 4, 6, 3, // right
+        4, 5, 6, 5, 7, 6, // back
+        5, 0, 7, 0, 2, 7, // left
+        5, 4, 0, 4, 1, 0, // top
+        2, 3, 7, 3, 6, 7, // bottom
+    ];
+
+    // Create buffers
+    let vertex_bytes = unsafe {
+        std::slice::from_raw_parts(
+            vertex_data.as_ptr() as *const u8,
+            mem::size_of_val(&vertex_data),
+        )
+    };
+    let vertex_buffer = device.create_buffer(BufferType::Vertex, vertex_bytes)?;
 
-        let vertex_input_state = device.create_vertex_input_state(&attributes, &bindings)?;
+    let index_bytes = unsafe {
+        std::slice::from_raw_parts(
+            index_data.as_ptr() as *const u8,
+            mem::size_of_val(&index_data),
+        )
+    };
+    let index_buffer = device.create_buffer(BufferType::Index, index_bytes)?;
+
+    // Create vertex input state
+    let attributes = vec![
+        VertexAttribute {
+            buffer_index: 0,
+            format: VertexFormat::Float3,
+            offset: 0,
+            name: "position".to_string(),
+            location: 0,
+            input_rate: InputRate::Vertex,
+        },
+        VertexAttribute {
+            buffer_index: 0,
+            format: VertexFormat::Float3,
+            offset: mem::size_of::<[f32; 3]>() as u32,
+            name: "color_in".to_string(),
+            location: 1,
+            input_rate: InputRate::Vertex,
+        },
+    ];
+
+    let bindings = vec![VertexBinding {
+        stride: mem::size_of::<VertexPosColor>() as u32,
+        input_rate: InputRate::Vertex,
+    }];
+
+    let vertex_input_state = device.create_vertex_input_state(&attributes, &bindings)?;
+
+    // Create shaders
+    let shader_stages =
+        device.create_shader_stages_metal(METAL_SHADER_SOURCE, "vertexShader", "fragmentShader")?;
+
+    Ok(GpuResources {
+        vertex_buffer,
+        index_buffer,
+        vertex_input_state,
+        shader_stages,
+    })
+}
 
-        // Create shaders
-        let shader_stages = device.create_shader_stages_metal(
-            METAL_SHADER_SOURCE,
-            "vertexShader",
-            "fragmentShader",
-        )?;
+impl ThreeCubesRenderSession {
+    pub fn new(device: &Device) -> Result<Self> {
+        let resources = create_gpu_resources(device)?;
 
         // Create command queue
         let command_queue = device.create_command_queue()?;
@@ -191,18 +214,63 @@ impl ThreeCubesRenderSession {
 
         Ok(ThreeCubesRenderSession {
             command_queue,
-            vertex_buffer,
-            index_buffer,
-            vertex_input_state,
-            shader_stages,
+            vertex_buffer: resources.vertex_buffer,
+            index_buffer: resources.index_buffer,
+            vertex_input_state: resources.vertex_input_state,
+            shader_stages: resources.shader_stages,
             pipeline_state: None,
             framebuffer: None,
+            offscreen_depth_texture: None,
             cubes,
             start_time: now,
             last_frame_time: now,
         })
     }
 
+    /// Rebuild every GPU resource this session owns against a freshly
+    /// recreated `Device`, without losing cube animation state. Call this
+    /// after `Platform::get_frame_textures`/`get_frame_textures_for`
+    /// returns `Error::DeviceLost` and a new `Platform`/`Device` has been
+    /// created to replace the lost one.
+    pub fn recover(&mut self, device: &Device) -> Result<()> {
+        let resources = create_gpu_resources(device)?;
+
+        self.command_queue = device.create_command_queue()?;
+        self.vertex_buffer = resources.vertex_buffer;
+        self.index_buffer = resources.index_buffer;
+        self.vertex_input_state = resources.vertex_input_state;
+        self.shader_stages = resources.shader_stages;
+        // The pipeline and framebuffer were built against the lost
+        // device's textures; `render` recreates them lazily.
+        self.pipeline_state = None;
+        self.framebuffer = None;
+        self.offscreen_depth_texture = None;
+
+        Ok(())
+    }
+
+    /// Render one frame into a caller-supplied offscreen color target
+    /// instead of a platform-provided drawable, allocating a matching
+    /// depth buffer on first use. Lets CI compare `color_texture`'s
+    /// pixels (via `Texture::read_pixels`) against a golden image without
+    /// a window system attached.
+    pub fn render_to_texture(&mut self, device: &Device, color_texture: &Texture) -> Result<()> {
+        if self.offscreen_depth_texture.is_none() {
+            self.offscreen_depth_texture = Some(device.create_offscreen_texture(
+                TextureFormat::Depth32Float,
+                color_texture.width(),
+                color_texture.height(),
+            )?);
+        }
+
+        // Borrow-checker workaround: `render` needs `&self.offscreen_depth_texture`
+        // while also taking `&mut self`, so move it out and back in.
+        let depth_texture = self.offscreen_depth_texture.take().unwrap();
+        let result = self.render(device, color_texture, &depth_texture);
+        self.offscreen_depth_texture = Some(depth_texture);
+        result
+    }
+
     pub fn render(
         &mut self,
         device: &Device,
@@ -238,6 +306,7 @@ impl ThreeCubesRenderSession {
                 unsafe { std::mem::transmute(depth_format_raw) },
                 CullMode::Back,
                 WindingMode::Clockwise,
+                BlendState::default(),
             )?);
         }
 